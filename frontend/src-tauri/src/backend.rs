@@ -1,22 +1,104 @@
 use anyhow::{anyhow, Context, Result};
-use std::fs::{create_dir_all, OpenOptions};
-use std::io::Write;
-use std::path::PathBuf;
+use std::fs::{create_dir_all, File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::net::ToSocketAddrs;
+use std::path::{Path, PathBuf};
 use std::sync::Mutex;
 use std::time::Duration;
 use tauri::async_runtime::Receiver;
 use tauri::path::BaseDirectory;
-use tauri::{AppHandle, Manager};
-use tauri_plugin_shell::process::{CommandChild, CommandEvent};
+use tauri::{AppHandle, Emitter, Manager};
+use tauri_plugin_shell::process::{CommandChild, CommandEvent, TerminatedPayload};
 use tauri_plugin_shell::ShellExt;
+use tauri_plugin_store::StoreExt;
 
 #[cfg(windows)]
 use std::os::windows::process::CommandExt;
 
+/// Tail of the backend log, returned to the frontend for display or bug reports.
+#[derive(serde::Serialize)]
+pub struct BackendLogTail {
+    pub path: PathBuf,
+    pub lines: Vec<String>,
+}
+
+/// User-configurable backend launch settings, persisted via the `tauri-plugin-store` store so
+/// power users and developers can point the desktop shell at a locally-developed backend
+/// without rebuilding. Any field left unset falls back to the bundled default.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Default)]
+pub struct BackendConfig {
+    #[serde(default)]
+    pub backend_dir: Option<PathBuf>,
+    #[serde(default)]
+    pub module: Option<String>,
+    #[serde(default)]
+    pub extra_args: Vec<String>,
+    #[serde(default)]
+    pub env: std::collections::HashMap<String, String>,
+    /// Host the readiness probe polls. Falls back to [`BACKEND_HOST`].
+    #[serde(default)]
+    pub host: Option<String>,
+    /// Port the readiness probe polls. Falls back to [`BACKEND_PORT`].
+    #[serde(default)]
+    pub port: Option<u16>,
+}
+
+const BACKEND_CONFIG_STORE: &str = "backend-config.json";
+const BACKEND_CONFIG_KEY: &str = "backend_config";
+
+impl BackendConfig {
+    fn load(app: &AppHandle) -> Result<Self> {
+        let store = app
+            .store(BACKEND_CONFIG_STORE)
+            .context("Failed to open backend config store")?;
+
+        match store.get(BACKEND_CONFIG_KEY) {
+            Some(value) => serde_json::from_value(value)
+                .context("Failed to parse stored backend config"),
+            None => Ok(Self::default()),
+        }
+    }
+
+    fn save(&self, app: &AppHandle) -> Result<()> {
+        let store = app
+            .store(BACKEND_CONFIG_STORE)
+            .context("Failed to open backend config store")?;
+
+        let value = serde_json::to_value(self).context("Failed to serialize backend config")?;
+        store.set(BACKEND_CONFIG_KEY, value);
+        store.save().context("Failed to persist backend config")?;
+
+        Ok(())
+    }
+}
+
+/// A single line of `uv sync` output, emitted as a `dep-install-progress` event.
+#[derive(serde::Serialize, Clone)]
+struct DepInstallProgress<'a> {
+    line: &'a str,
+    phase: &'static str,
+}
+
+/// State shared between the public API and the log-streaming/supervisor threads.
+#[derive(Default)]
+struct SupervisorState {
+    processes: Vec<CommandChild>,
+    /// Set by `stop_all` so the supervisor can tell an intentional kill from a crash.
+    shutting_down: bool,
+    /// Consecutive unexpected-exit count, reset once the backend stays up past
+    /// [`STABILITY_WINDOW_SECS`].
+    restart_attempts: u32,
+}
+
 /// Backend process manager
 pub struct BackendManager {
-    processes: Mutex<Vec<CommandChild>>,
+    state: Mutex<SupervisorState>,
     backend_path: PathBuf,
+    module: String,
+    extra_args: Vec<String>,
+    env: std::collections::HashMap<String, String>,
+    host: String,
+    port: u16,
     log_dir: PathBuf,
     app: AppHandle,
 }
@@ -25,28 +107,85 @@ const MAIN_MODULE: &str = "valuecell.server.main";
 const EXIT_COMMAND: &[u8] = b"__EXIT__\n";
 const GRACEFUL_TIMEOUT_SECS: u64 = 3;
 
+const BACKEND_HOST: &str = "127.0.0.1";
+const BACKEND_PORT: u16 = 8000;
+const READINESS_TIMEOUT_SECS: u64 = 120;
+const READINESS_POLL_INTERVAL_MS: u64 = 500;
+
+const INITIAL_BACKOFF_SECS: u64 = 1;
+const MAX_BACKOFF_SECS: u64 = 30;
+const STABILITY_WINDOW_SECS: u64 = 60;
+const MAX_CONSECUTIVE_FAILURES: u32 = 5;
+
 impl BackendManager {
-    fn wait_until_terminated(mut rx: Receiver<CommandEvent>) {
-        while let Some(event) = rx.blocking_recv() {
-            if matches!(event, CommandEvent::Terminated(_)) {
-                break;
+    /// Walk the system process table to find every transitive descendant of `root_pid`,
+    /// ordered leaf-first (deepest descendants before their ancestors) so children are
+    /// always signalled before the parent that would otherwise reap/orphan them.
+    fn descendants_leaf_first(system: &sysinfo::System, root_pid: u32) -> Vec<sysinfo::Pid> {
+        let mut children_by_parent: std::collections::HashMap<u32, Vec<sysinfo::Pid>> =
+            std::collections::HashMap::new();
+        for (pid, process) in system.processes() {
+            if let Some(parent) = process.parent() {
+                children_by_parent
+                    .entry(parent.as_u32())
+                    .or_default()
+                    .push(*pid);
             }
         }
+
+        let mut ordered = Vec::new();
+        let mut queue = std::collections::VecDeque::from([root_pid]);
+        while let Some(pid) = queue.pop_front() {
+            if let Some(children) = children_by_parent.get(&pid) {
+                for &child in children {
+                    ordered.push(child);
+                    queue.push_back(child.as_u32());
+                }
+            }
+        }
+
+        // BFS above visits parents before children; reverse so leaves come first.
+        ordered.reverse();
+        ordered
     }
 
+    /// Terminate the backend's whole process tree. Builds the system process table with
+    /// `sysinfo` and signals descendants leaf-first: SIGTERM first (via `nix` on Unix,
+    /// `taskkill /T` on Windows), then waits out [`GRACEFUL_TIMEOUT_SECS`] and SIGKILLs any
+    /// survivors found by refreshing the table again. This avoids orphaned `uv`/Python
+    /// grandchildren without depending on external binaries like `pkill` being present.
     fn kill_descendants_best_effort(&self, parent_pid: u32) {
-        let pid_str = parent_pid.to_string();
+        let mut system = sysinfo::System::new();
+        system.refresh_processes(sysinfo::ProcessesToUpdate::All, true);
+
+        let descendants = Self::descendants_leaf_first(&system, parent_pid);
+        if descendants.is_empty() {
+            log::info!("No descendants found for process {}", parent_pid);
+            return;
+        }
+
+        log::info!(
+            "Found {} descendant(s) of {}, requesting graceful shutdown",
+            descendants.len(),
+            parent_pid
+        );
+
+        #[cfg(not(windows))]
+        for pid in &descendants {
+            if let Err(e) = nix::sys::signal::kill(
+                nix::unistd::Pid::from_raw(pid.as_u32() as i32),
+                nix::sys::signal::Signal::SIGTERM,
+            ) {
+                log::warn!("Failed to send SIGTERM to {}: {}", pid, e);
+            }
+        }
 
         #[cfg(windows)]
         {
-            // On Windows, use taskkill to forcefully terminate the process tree
-            // /F = Force
-            // /T = Tree (child processes)
-            // /PID = Process ID
             log::info!("Issued taskkill for descendants of {}", parent_pid);
             // We use std::process::Command directly to avoid needing to configure permissions for taskkill
             if let Err(e) = std::process::Command::new("taskkill")
-                .args(["/F", "/T", "/PID", &pid_str])
+                .args(["/T", "/PID", &parent_pid.to_string()])
                 .creation_flags(0x08000000) // CREATE_NO_WINDOW
                 .output()
             {
@@ -54,55 +193,55 @@ impl BackendManager {
             }
         }
 
+        std::thread::sleep(Duration::from_secs(GRACEFUL_TIMEOUT_SECS));
+
+        system.refresh_processes(sysinfo::ProcessesToUpdate::All, true);
+        let survivors = Self::descendants_leaf_first(&system, parent_pid);
+        if survivors.is_empty() {
+            log::info!("All descendants of {} terminated gracefully", parent_pid);
+            return;
+        }
+
+        log::warn!(
+            "{} descendant(s) of {} survived the graceful timeout, sending SIGKILL",
+            survivors.len(),
+            parent_pid
+        );
+
         #[cfg(not(windows))]
-        {
-            // Try to kill all descendants of the given PID (macOS/Linux)
-            // This is best-effort and ignores errors on platforms without `pkill`.
-            // First, send SIGINT (Ctrl+C equivalent) and wait up to 5 seconds.
-            // If processes are still running, escalate to SIGKILL.
-
-            // Send SIGINT (Ctrl+C equivalent)
-            if let Ok((_rx, _child)) = self
-                .app
-                .shell()
-                .command("pkill")
-                .args(["-INT", "-P", &pid_str])
-                .spawn()
-            {
-                log::info!(
-                    "Issued SIGINT (Ctrl+C) pkill for descendants of {}",
-                    parent_pid
-                );
+        for pid in &survivors {
+            if let Err(e) = nix::sys::signal::kill(
+                nix::unistd::Pid::from_raw(pid.as_u32() as i32),
+                nix::sys::signal::Signal::SIGKILL,
+            ) {
+                log::warn!("Failed to send SIGKILL to {}: {}", pid, e);
             }
+        }
 
-            // Wait up to 3 seconds for graceful termination
-            std::thread::sleep(Duration::from_secs(3));
-
-            // Escalate to SIGKILL if processes are still running
-            if let Ok((_rx, _child)) = self
-                .app
-                .shell()
-                .command("pkill")
-                .args(["-KILL", "-P", &pid_str])
-                .spawn()
+        #[cfg(windows)]
+        {
+            log::info!("Issued forceful taskkill for descendants of {}", parent_pid);
+            if let Err(e) = std::process::Command::new("taskkill")
+                .args(["/F", "/T", "/PID", &parent_pid.to_string()])
+                .creation_flags(0x08000000) // CREATE_NO_WINDOW
+                .output()
             {
-                log::info!(
-                    "Issued SIGKILL (forceful) pkill for descendants of {}",
-                    parent_pid
-                );
+                log::error!("Failed to execute taskkill: {}", e);
             }
         }
     }
 
     fn spawn_backend_process(&self) -> Result<(Receiver<CommandEvent>, CommandChild)> {
-        log::info!("Command: uv run -m {}", MAIN_MODULE);
+        log::info!("Command: uv run -m {} {:?}", self.module, self.extra_args);
 
         let sidecar_command = self
             .app
             .shell()
             .sidecar("uv")
             .context("Failed to create uv sidecar command")?
-            .args(["run", "-m", MAIN_MODULE])
+            .args(["run", "-m", &self.module])
+            .args(&self.extra_args)
+            .envs(self.env.clone())
             .current_dir(&self.backend_path);
 
         sidecar_command
@@ -137,12 +276,28 @@ impl BackendManager {
     }
 
     pub fn new(app: AppHandle) -> Result<Self> {
+        let config = BackendConfig::load(&app).unwrap_or_else(|e| {
+            log::warn!("Failed to load backend config override: {:#}, using defaults", e);
+            BackendConfig::default()
+        });
+
         let resource_root = app
             .path()
             .resolve(".", BaseDirectory::Resource)
             .context("Failed to resolve resource root")?;
 
-        let backend_path = resource_root.join("backend");
+        let default_backend_path = resource_root.join("backend");
+        let backend_path = match config.backend_dir {
+            Some(dir) if dir.exists() => dir,
+            Some(dir) => {
+                log::warn!(
+                    "Configured backend directory {:?} does not exist, falling back to bundled backend",
+                    dir
+                );
+                default_backend_path
+            }
+            None => default_backend_path,
+        };
         if !backend_path.exists() {
             return Err(anyhow!("Backend directory not found at {:?}", backend_path));
         }
@@ -155,12 +310,23 @@ impl BackendManager {
 
         create_dir_all(&log_dir).context("Failed to create log directory")?;
 
+        let module = config.module.unwrap_or_else(|| MAIN_MODULE.to_string());
+        let host = config.host.unwrap_or_else(|| BACKEND_HOST.to_string());
+        let port = config.port.unwrap_or(BACKEND_PORT);
+
         log::info!("Backend path: {:?}", backend_path);
+        log::info!("Backend module: {}", module);
+        log::info!("Backend readiness address: {}:{}", host, port);
         log::info!("Log directory: {:?}", log_dir);
 
         Ok(Self {
-            processes: Mutex::new(Vec::new()),
+            state: Mutex::new(SupervisorState::default()),
             backend_path,
+            module,
+            extra_args: config.extra_args,
+            env: config.env,
+            host,
+            port,
             log_dir,
             app,
         })
@@ -224,48 +390,423 @@ impl BackendManager {
             .current_dir(&self.backend_path);
 
         let (rx, _child) = sidecar_command.spawn().context("Failed to spawn uv sync")?;
-        Self::wait_until_terminated(rx);
+        self.stream_uv_sync_progress(rx)?;
 
         log::info!("âœ“ Dependencies installed/verified");
         Ok(())
     }
 
+    /// Classify a `uv sync` output line into a coarse phase the frontend can render a progress
+    /// step for. Falls back to `"other"` for lines that don't match a known phase.
+    fn classify_uv_sync_phase(line: &str) -> &'static str {
+        let lower = line.to_lowercase();
+        if lower.contains("resolv") {
+            "resolving"
+        } else if lower.contains("download") {
+            "downloading"
+        } else if lower.contains("install") || lower.contains("audit") {
+            "installing"
+        } else {
+            "other"
+        }
+    }
+
+    /// Consume `uv sync`'s output as it streams in, mirroring each line into `uv-sync.log`
+    /// (like [`Self::stream_to_file`] does for the backend) and emitting a `dep-install-progress`
+    /// event per line so the frontend can render a live install log instead of a blank screen.
+    fn stream_uv_sync_progress(&self, mut rx: Receiver<CommandEvent>) -> Result<()> {
+        let log_path = self.log_dir.join("uv-sync.log");
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&log_path)
+            .with_context(|| format!("Failed to open uv sync log file {:?}", log_path))?;
+
+        while let Some(event) = rx.blocking_recv() {
+            match event {
+                CommandEvent::Stdout(line) | CommandEvent::Stderr(line) => {
+                    let text = String::from_utf8_lossy(&line);
+                    let text = text.trim_end_matches('\n');
+
+                    if let Err(err) = writeln!(file, "{}", text) {
+                        log::error!("Failed to write uv sync log line: {}", err);
+                    }
+
+                    let _ = self.app.emit(
+                        "dep-install-progress",
+                        DepInstallProgress {
+                            line: text,
+                            phase: Self::classify_uv_sync_phase(text),
+                        },
+                    );
+                }
+                CommandEvent::Error(err) => {
+                    log::error!("uv sync process error: {}", err);
+                }
+                CommandEvent::Terminated(payload) => {
+                    log::info!(
+                        "uv sync terminated (code: {:?}, signal: {:?})",
+                        payload.code,
+                        payload.signal
+                    );
+
+                    if payload.code.unwrap_or(1) != 0 {
+                        let message = format!(
+                            "uv sync failed (code: {:?}, signal: {:?})",
+                            payload.code, payload.signal
+                        );
+                        let _ = self.app.emit("dep-install-failed", message.clone());
+                        return Err(anyhow!(message));
+                    }
+
+                    return Ok(());
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn start_all(&self) -> Result<()> {
+        let _ = self.app.emit("backend-starting", ());
+
+        let _ = self.app.emit("backend-installing-deps", ());
         self.install_dependencies()?;
 
-        let mut processes = self.processes.lock().unwrap();
+        let mut state = self.state.lock().unwrap();
+        state.shutting_down = false;
+        state.restart_attempts = 0;
+        state.processes.clear();
 
         match self.spawn_backend_process() {
             Ok((rx, child)) => {
                 self.stream_backend_logs(rx);
                 log::info!("Process {} added to process list", child.pid());
-                processes.push(child);
+                self.watch_for_readiness();
+                state.processes.push(child);
+            }
+            Err(e) => {
+                log::error!("Failed to start backend server: {}", e);
+                let _ = self.app.emit("backend-failed", e.to_string());
             }
-            Err(e) => log::error!("Failed to start backend server: {}", e),
         }
 
         Ok(())
     }
 
+    /// Called from the log-streaming thread once the backend process has actually exited
+    /// (`CommandEvent::Terminated`). Restarts it with exponential backoff unless the exit was
+    /// caused by `stop_all`, or gives up (emitting `backend-crashed`) after too many consecutive
+    /// failures.
+    fn handle_unexpected_exit(&self, payload: TerminatedPayload) {
+        log::warn!(
+            "Handling unexpected backend exit (code: {:?}, signal: {:?})",
+            payload.code,
+            payload.signal
+        );
+
+        let attempt = {
+            let mut state = self.state.lock().unwrap();
+            if state.shutting_down {
+                log::info!("Backend exit was expected, not restarting");
+                return;
+            }
+            state.restart_attempts += 1;
+            state.restart_attempts
+        };
+
+        if attempt > MAX_CONSECUTIVE_FAILURES {
+            log::error!(
+                "Backend crashed {} times in a row, giving up",
+                attempt
+            );
+            let _ = self.app.emit(
+                "backend-crashed",
+                "backend crashed too many times in a row, giving up",
+            );
+            return;
+        }
+
+        let backoff = Duration::from_secs(
+            (INITIAL_BACKOFF_SECS.saturating_mul(1 << (attempt - 1))).min(MAX_BACKOFF_SECS),
+        );
+        log::warn!(
+            "Backend exited unexpectedly, restarting in {:?} (attempt {})",
+            backoff,
+            attempt
+        );
+        let _ = self.app.emit(
+            "backend-crashed",
+            format!("backend crashed, restarting in {:?} (attempt {})", backoff, attempt),
+        );
+        std::thread::sleep(backoff);
+
+        {
+            let state = self.state.lock().unwrap();
+            if state.shutting_down {
+                log::info!("Shutdown requested while waiting to restart, aborting restart");
+                return;
+            }
+        }
+
+        match self.spawn_backend_process() {
+            Ok((rx, child)) => {
+                log::info!("Restarted backend process {}", child.pid());
+                self.stream_backend_logs(rx);
+                self.watch_for_readiness();
+                let mut state = self.state.lock().unwrap();
+                // The process that just exited is still sitting in here; drop it before
+                // tracking the replacement so `stop_all` never tries to kill a dead handle.
+                state.processes.clear();
+                state.processes.push(child);
+                drop(state);
+                self.watch_for_stability(attempt);
+            }
+            Err(e) => {
+                log::error!("Failed to restart backend: {}", e);
+                let _ = self.app.emit("backend-failed", e.to_string());
+            }
+        }
+    }
+
+    /// After a restart, clear the failure counter if the process survives past
+    /// [`STABILITY_WINDOW_SECS`] without crashing again.
+    fn watch_for_stability(&self, attempt: u32) {
+        let app = self.app.clone();
+        std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_secs(STABILITY_WINDOW_SECS));
+            let Some(manager) = app.try_state::<BackendManager>() else {
+                return;
+            };
+            let mut state = manager.state.lock().unwrap();
+            if state.restart_attempts == attempt {
+                log::info!(
+                    "Backend stable for {}s, resetting restart counter",
+                    STABILITY_WINDOW_SECS
+                );
+                state.restart_attempts = 0;
+            }
+        });
+    }
+
+    /// Poll the backend's port on a background thread until it accepts connections or
+    /// [`READINESS_TIMEOUT_SECS`] elapses, emitting lifecycle events the frontend can use to
+    /// show a real splash/loading state.
+    fn watch_for_readiness(&self) {
+        let app = self.app.clone();
+        let addr = format!("{}:{}", self.host, self.port);
+        std::thread::spawn(move || {
+            let deadline =
+                std::time::Instant::now() + Duration::from_secs(READINESS_TIMEOUT_SECS);
+
+            loop {
+                // Resolve on every attempt rather than once up front: `host` is a free-form
+                // string (e.g. "localhost") that a `SocketAddr` parse would reject outright,
+                // and re-resolving also tolerates the backend's hostname coming up slightly
+                // after the process itself does.
+                let reachable = match addr.to_socket_addrs() {
+                    Ok(candidates) => candidates.into_iter().any(|socket_addr| {
+                        std::net::TcpStream::connect_timeout(
+                            &socket_addr,
+                            Duration::from_millis(READINESS_POLL_INTERVAL_MS),
+                        )
+                        .is_ok()
+                    }),
+                    Err(e) => {
+                        log::warn!("Failed to resolve backend address {:?}: {}", addr, e);
+                        false
+                    }
+                };
+
+                if reachable {
+                    log::info!("Backend is accepting connections on {}", addr);
+                    let _ = app.emit("backend-ready", ());
+                    return;
+                }
+
+                if std::time::Instant::now() >= deadline {
+                    log::error!(
+                        "Backend did not become ready within {}s",
+                        READINESS_TIMEOUT_SECS
+                    );
+                    let _ = app.emit(
+                        "backend-failed",
+                        format!("Backend did not become ready within {READINESS_TIMEOUT_SECS}s"),
+                    );
+                    return;
+                }
+
+                std::thread::sleep(Duration::from_millis(READINESS_POLL_INTERVAL_MS));
+            }
+        });
+    }
+
     /// Stop all backend processes
     pub fn stop_all(&self) {
-        let mut processes = self.processes.lock().unwrap();
-        for process in processes.drain(..) {
+        let mut state = self.state.lock().unwrap();
+        state.shutting_down = true;
+        let processes: Vec<_> = state.processes.drain(..).collect();
+        drop(state);
+
+        for process in processes {
             self.request_graceful_then_kill(process);
         }
     }
 
     fn stream_backend_logs(&self, rx: Receiver<CommandEvent>) {
         let log_path = self.log_dir.join("backend.log");
-        std::thread::spawn(move || Self::stream_to_file(rx, log_path));
+        let app = self.app.clone();
+        std::thread::spawn(move || {
+            if let Some(payload) = Self::stream_to_file(rx, log_path) {
+                if let Some(manager) = app.try_state::<BackendManager>() {
+                    manager.handle_unexpected_exit(payload);
+                }
+            }
+        });
     }
 
-    fn stream_to_file(mut rx: Receiver<CommandEvent>, log_path: PathBuf) {
+    /// Path to the most recently modified `backend*.log` file in [`Self::log_dir`]. Matched by
+    /// name rather than just the `.log` extension so this can't pick up `uv-sync.log`, which
+    /// lives in the same directory and can be touched more recently than the backend log.
+    fn latest_log_path(&self) -> Result<PathBuf> {
+        let mut latest: Option<(PathBuf, std::time::SystemTime)> = None;
+
+        for entry in std::fs::read_dir(&self.log_dir)
+            .with_context(|| format!("Failed to read log directory {:?}", self.log_dir))?
+        {
+            let entry = entry.context("Failed to read log directory entry")?;
+            let path = entry.path();
+
+            let file_name = path.file_name().and_then(|name| name.to_str()).unwrap_or("");
+            let is_backend_log = file_name.starts_with("backend")
+                && path.extension().and_then(|ext| ext.to_str()) == Some("log");
+            if !is_backend_log {
+                continue;
+            }
+
+            let modified = entry
+                .metadata()
+                .and_then(|meta| meta.modified())
+                .unwrap_or(std::time::SystemTime::UNIX_EPOCH);
+
+            if latest.as_ref().map_or(true, |(_, ts)| modified > *ts) {
+                latest = Some((path, modified));
+            }
+        }
+
+        latest
+            .map(|(path, _)| path)
+            .ok_or_else(|| anyhow!("No backend log files found in {:?}", self.log_dir))
+    }
+
+    /// Read the last `max_lines` lines of `path`.
+    fn tail_file(path: &Path, max_lines: usize) -> Result<Vec<String>> {
+        let file = File::open(path).with_context(|| format!("Failed to open {:?}", path))?;
+        let mut tail = std::collections::VecDeque::with_capacity(max_lines);
+
+        for line in BufReader::new(file).lines() {
+            let line = line.with_context(|| format!("Failed to read line from {:?}", path))?;
+            if tail.len() == max_lines {
+                tail.pop_front();
+            }
+            tail.push_back(line);
+        }
+
+        Ok(tail.into_iter().collect())
+    }
+
+    /// Return the path to the most recent log file together with its tail, for display in the UI.
+    pub fn tail_latest_log(&self, max_lines: usize) -> Result<BackendLogTail> {
+        let path = self.latest_log_path()?;
+        let lines = Self::tail_file(&path, max_lines)?;
+        Ok(BackendLogTail { path, lines })
+    }
+
+    /// Bundle the backend log(s), the app log and the client ID into a single zip file
+    /// the user can attach to a bug report. Returns the path to the written archive.
+    pub async fn collect_diagnostics(&self) -> Result<PathBuf> {
+        let app_log_dir = self
+            .app
+            .path()
+            .app_log_dir()
+            .context("Failed to get app log directory")?;
+
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let archive_path = app_log_dir.join(format!("diagnostics-{timestamp}.zip"));
+        let archive_file = File::create(&archive_path)
+            .with_context(|| format!("Failed to create diagnostics archive at {:?}", archive_path))?;
+
+        let mut zip = zip::ZipWriter::new(archive_file);
+        let options =
+            zip::write::FileOptions::<()>::default().compression_method(zip::CompressionMethod::Deflated);
+
+        for entry in std::fs::read_dir(&self.log_dir)
+            .with_context(|| format!("Failed to read log directory {:?}", self.log_dir))?
+        {
+            let path = entry.context("Failed to read log directory entry")?.path();
+            if path.extension().and_then(|ext| ext.to_str()) == Some("log") {
+                Self::add_file_to_zip(&mut zip, &path, &options, "backend")?;
+            }
+        }
+
+        for entry in std::fs::read_dir(&app_log_dir)
+            .with_context(|| format!("Failed to read app log directory {:?}", app_log_dir))?
+        {
+            let path = entry.context("Failed to read app log directory entry")?.path();
+            if path.is_file() && path.extension().and_then(|ext| ext.to_str()) == Some("log") {
+                Self::add_file_to_zip(&mut zip, &path, &options, "app")?;
+            }
+        }
+
+        if let Ok(client_id) = crate::system::get_or_create_client_id(&self.app).await {
+            zip.start_file("client_id.txt", options)
+                .context("Failed to add client_id.txt to diagnostics archive")?;
+            zip.write_all(client_id.as_bytes())
+                .context("Failed to write client_id.txt to diagnostics archive")?;
+        }
+
+        zip.finish().context("Failed to finalize diagnostics archive")?;
+
+        log::info!("Diagnostics archive written to {:?}", archive_path);
+        Ok(archive_path)
+    }
+
+    fn add_file_to_zip(
+        zip: &mut zip::ZipWriter<File>,
+        path: &Path,
+        options: &zip::write::FileOptions<()>,
+        prefix: &str,
+    ) -> Result<()> {
+        let file_name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .ok_or_else(|| anyhow!("Invalid log file name: {:?}", path))?;
+
+        zip.start_file(format!("{prefix}/{file_name}"), *options)
+            .with_context(|| format!("Failed to add {:?} to diagnostics archive", path))?;
+
+        let contents =
+            std::fs::read(path).with_context(|| format!("Failed to read {:?}", path))?;
+        zip.write_all(&contents)
+            .with_context(|| format!("Failed to write {:?} to diagnostics archive", path))?;
+
+        Ok(())
+    }
+
+    /// Stream backend output to `log_path` until the process actually exits. Returns the
+    /// termination payload only when the loop ended because of `CommandEvent::Terminated` —
+    /// a stream/IO error (`CommandEvent::Error`) or a closed channel is not proof the child
+    /// died, so callers must not treat those as a crash.
+    fn stream_to_file(mut rx: Receiver<CommandEvent>, log_path: PathBuf) -> Option<TerminatedPayload> {
         let mut file = match OpenOptions::new().create(true).append(true).open(&log_path) {
             Ok(file) => file,
             Err(err) => {
                 log::error!("Failed to open backend log file {:?}: {}", log_path, err);
-                return;
+                return None;
             }
         };
 
@@ -288,11 +829,13 @@ impl BackendManager {
                         payload.code,
                         payload.signal
                     );
-                    break;
+                    return Some(payload);
                 }
                 _ => {}
             }
         }
+
+        None
     }
 }
 
@@ -301,3 +844,34 @@ impl Drop for BackendManager {
         self.stop_all();
     }
 }
+
+const LOG_TAIL_LINES: usize = 200;
+
+/// Return the path and tail of the most recent backend log file, for display in the UI.
+#[tauri::command]
+pub fn get_backend_log(manager: tauri::State<BackendManager>) -> Result<BackendLogTail, String> {
+    manager
+        .tail_latest_log(LOG_TAIL_LINES)
+        .map_err(|e| e.to_string())
+}
+
+/// Zip the backend log(s), app log and client ID into a single file the user can attach
+/// to a bug report. Returns the path to the written archive.
+#[tauri::command]
+pub async fn collect_diagnostics(manager: tauri::State<'_, BackendManager>) -> Result<PathBuf, String> {
+    manager.collect_diagnostics().await.map_err(|e| e.to_string())
+}
+
+/// Read the persisted backend launch overrides, if any have been set.
+#[tauri::command]
+pub fn get_backend_config(app: AppHandle) -> Result<BackendConfig, String> {
+    BackendConfig::load(&app).map_err(|e| e.to_string())
+}
+
+/// Persist backend launch overrides (directory, module, extra args, env vars). They are read
+/// when the backend manager is created, so they take effect on the next app launch; automatic
+/// restarts after a crash keep the settings loaded at launch.
+#[tauri::command]
+pub fn set_backend_config(app: AppHandle, config: BackendConfig) -> Result<(), String> {
+    config.save(&app).map_err(|e| e.to_string())
+}
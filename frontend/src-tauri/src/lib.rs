@@ -1,7 +1,9 @@
 mod backend;
 mod system;
 
-use backend::BackendManager;
+use backend::{
+    collect_diagnostics, get_backend_config, get_backend_log, set_backend_config, BackendManager,
+};
 use system::get_client_id;
 use tauri::Manager;
 
@@ -26,7 +28,13 @@ pub fn run() {
         )
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_shell::init())
-        .invoke_handler(tauri::generate_handler![get_client_id])
+        .invoke_handler(tauri::generate_handler![
+            get_client_id,
+            get_backend_log,
+            collect_diagnostics,
+            get_backend_config,
+            set_backend_config
+        ])
         .setup(|app| {
             let handle = app.handle().clone();
 
@@ -38,12 +46,14 @@ pub fn run() {
                 }
             };
 
-            if let Err(e) = manager.start_all() {
+            // Manage the manager before starting the backend so the log-streaming thread can
+            // always reach it via `try_state` — including if the backend crashes immediately.
+            app.manage(manager);
+
+            if let Err(e) = app.state::<BackendManager>().start_all() {
                 log::error!("❌ Failed to start backend: {e:#}");
             }
 
-            app.manage(manager);
-
             Ok(())
         })
         .on_window_event(|window, event| {